@@ -1,10 +1,13 @@
 use crate::error::PyPolarsEr;
+use crate::series::PySeries;
 use polars::lazy::dsl;
 use polars::lazy::dsl::Operator;
 use polars::prelude::*;
 use pyo3::prelude::*;
-use pyo3::types::{PyFloat, PyInt};
+use pyo3::types::{PyBytes, PyFloat, PyInt, PyTuple};
 use pyo3::PyNumberProtocol;
+use pyo3::PyObjectProtocol;
+use regex::Regex;
 
 #[pyclass]
 #[repr(transparent)]
@@ -29,6 +32,18 @@ impl PyNumberProtocol for PyExpr {
     }
 }
 
+#[pyproto]
+impl PyObjectProtocol for PyExpr {
+    fn __reduce__(&self) -> PyResult<(PyObject, PyObject)> {
+        Python::with_gil(|py| {
+            let buf = self.to_bytes(py)?;
+            let constructor = py.get_type::<PyExpr>().getattr("from_bytes")?.to_object(py);
+            let args = PyTuple::new(py, &[buf]).to_object(py);
+            Ok((constructor, args))
+        })
+    }
+}
+
 #[pymethods]
 impl PyExpr {
     #[text_signature = "($self, other)"]
@@ -113,12 +128,12 @@ impl PyExpr {
     }
 
     #[text_signature = "($self, data_type)"]
-    pub fn cast(&self, data_type: &str) -> PyExpr {
+    pub fn cast(&self, data_type: &str) -> PyResult<PyExpr> {
         // TODO! accept the DataType objects.
 
-        let dt = str_to_arrow_type(data_type);
+        let dt = str_to_arrow_type(data_type)?;
         let expr = self.inner.clone().cast(dt);
-        expr.into()
+        Ok(expr.into())
     }
     #[text_signature = "($self, reverse)"]
     pub fn sort(&self, reverse: bool) -> PyExpr {
@@ -156,6 +171,33 @@ impl PyExpr {
     pub fn quantile(&self, quantile: f64) -> PyExpr {
         self.clone().inner.quantile(quantile).into()
     }
+    /// Serialize the underlying `dsl::Expr` so it can be persisted or sent
+    /// over the wire and reconstructed with `from_bytes`. Expressions built
+    /// from a closure-backed `apply` (e.g. `str_replace`/`str_contains`) are
+    /// not representable this way and surface as an error rather than
+    /// silently dropping the closure.
+    pub fn to_bytes(&self, py: Python) -> PyResult<PyObject> {
+        if contains_closure(&self.inner) {
+            return Err(PyPolarsEr::Other(
+                "cannot serialize an expression built from a closure-backed apply/map \
+                 (e.g. str_replace, str_contains, PyExpr.map, PyExpr.apply); only \
+                 structural expressions round-trip through to_bytes"
+                    .into(),
+            )
+            .into());
+        }
+        let buf =
+            bincode::serialize(&self.inner).map_err(|e| PyPolarsEr::Other(format!("{:?}", e)))?;
+        Ok(PyBytes::new(py, &buf).to_object(py))
+    }
+
+    #[staticmethod]
+    pub fn from_bytes(buf: &[u8]) -> PyResult<PyExpr> {
+        let inner: dsl::Expr =
+            bincode::deserialize(buf).map_err(|e| PyPolarsEr::Other(format!("{:?}", e)))?;
+        Ok(inner.into())
+    }
+
     pub fn str_lengths(&self) -> PyExpr {
         let function = |s: Series| {
             let ca = s.utf8()?;
@@ -196,10 +238,210 @@ impl PyExpr {
         };
         self.clone().inner.apply(function, None).into()
     }
+
+    #[text_signature = "($self, pat, group)"]
+    pub fn str_extract(&self, pat: String, group: usize) -> PyExpr {
+        let function = move |s: Series| {
+            let ca = s.utf8()?;
+            let reg =
+                Regex::new(&pat).map_err(|e| PolarsError::Other(format!("{:?}", e).into()))?;
+            let out: Utf8Chunked = ca
+                .into_iter()
+                .map(|opt_v| {
+                    opt_v.and_then(|v| {
+                        reg.captures(v)
+                            .and_then(|cap| cap.get(group))
+                            .map(|m| m.as_str().to_string())
+                    })
+                })
+                .collect();
+            Ok(out.into_series())
+        };
+        self.clone().inner.apply(function, None).into()
+    }
+
+    #[text_signature = "($self, by)"]
+    pub fn str_split(&self, by: String) -> PyExpr {
+        let function = move |s: Series| {
+            let ca = s.utf8()?;
+            let mut builder =
+                ListUtf8ChunkedBuilder::new(ca.name(), ca.len(), ca.get_values_size());
+            for opt_v in ca {
+                match opt_v {
+                    Some(v) => builder.append_values_iter(v.split(&by as &str)),
+                    None => builder.append_null(),
+                }
+            }
+            Ok(builder.finish().into_series())
+        };
+        self.clone().inner.apply(function, None).into()
+    }
+
+    #[text_signature = "($self)"]
+    pub fn str_to_lowercase(&self) -> PyExpr {
+        let function = |s: Series| {
+            let ca = s.utf8()?;
+            let out: Utf8Chunked = ca.apply(|v| v.to_lowercase().into());
+            Ok(out.into_series())
+        };
+        self.clone().inner.apply(function, None).into()
+    }
+
+    #[text_signature = "($self)"]
+    pub fn str_to_uppercase(&self) -> PyExpr {
+        let function = |s: Series| {
+            let ca = s.utf8()?;
+            let out: Utf8Chunked = ca.apply(|v| v.to_uppercase().into());
+            Ok(out.into_series())
+        };
+        self.clone().inner.apply(function, None).into()
+    }
+
+    #[text_signature = "($self)"]
+    pub fn str_strip(&self) -> PyExpr {
+        let function = |s: Series| {
+            let ca = s.utf8()?;
+            let out: Utf8Chunked = ca.apply(|v| v.trim().into());
+            Ok(out.into_series())
+        };
+        self.clone().inner.apply(function, None).into()
+    }
+
+    #[text_signature = "($self)"]
+    pub fn str_lstrip(&self) -> PyExpr {
+        let function = |s: Series| {
+            let ca = s.utf8()?;
+            let out: Utf8Chunked = ca.apply(|v| v.trim_start().into());
+            Ok(out.into_series())
+        };
+        self.clone().inner.apply(function, None).into()
+    }
+
+    #[text_signature = "($self)"]
+    pub fn str_rstrip(&self) -> PyExpr {
+        let function = |s: Series| {
+            let ca = s.utf8()?;
+            let out: Utf8Chunked = ca.apply(|v| v.trim_end().into());
+            Ok(out.into_series())
+        };
+        self.clone().inner.apply(function, None).into()
+    }
+
+    #[text_signature = "($self, width)"]
+    pub fn str_zfill(&self, width: usize) -> PyExpr {
+        let function = move |s: Series| {
+            let ca = s.utf8()?;
+            let out: Utf8Chunked = ca.apply(|v| {
+                let len = v.chars().count();
+                if len >= width {
+                    v.into()
+                } else if let Some(stripped) = v.strip_prefix('-').or_else(|| v.strip_prefix('+')) {
+                    let sign = &v[..1];
+                    format!("{}{:0>width$}", sign, stripped, width = width - 1).into()
+                } else {
+                    format!("{:0>width$}", v, width = width).into()
+                }
+            });
+            Ok(out.into_series())
+        };
+        self.clone().inner.apply(function, None).into()
+    }
+
+    #[text_signature = "($self, width)"]
+    pub fn str_pad_start(&self, width: usize) -> PyExpr {
+        let function = move |s: Series| {
+            let ca = s.utf8()?;
+            let out: Utf8Chunked = ca.apply(|v| format!("{:>width$}", v, width = width).into());
+            Ok(out.into_series())
+        };
+        self.clone().inner.apply(function, None).into()
+    }
+
+    #[text_signature = "($self, width)"]
+    pub fn str_pad_end(&self, width: usize) -> PyExpr {
+        let function = move |s: Series| {
+            let ca = s.utf8()?;
+            let out: Utf8Chunked = ca.apply(|v| format!("{:<width$}", v, width = width).into());
+            Ok(out.into_series())
+        };
+        self.clone().inner.apply(function, None).into()
+    }
+
+    #[text_signature = "($self, start, length)"]
+    pub fn str_slice(&self, start: i64, length: Option<u64>) -> PyExpr {
+        let function = move |s: Series| {
+            let ca = s.utf8()?;
+            let out: Utf8Chunked = ca.apply(|v| {
+                let chars: Vec<char> = v.chars().collect();
+                let len = chars.len() as i64;
+                let start = if start < 0 {
+                    (len + start).max(0)
+                } else {
+                    start.min(len)
+                } as usize;
+                let end = match length {
+                    Some(length) => start.saturating_add(length as usize).min(chars.len()),
+                    None => chars.len(),
+                };
+                chars[start..end].iter().collect::<String>().into()
+            });
+            Ok(out.into_series())
+        };
+        self.clone().inner.apply(function, None).into()
+    }
+
+    /// Run an arbitrary Python callable on the whole `Series` this expression
+    /// produces (no group boundaries), coercing the result to `output_dtype`.
+    pub fn map(&self, f: PyObject, output_dtype: Option<&str>) -> PyResult<PyExpr> {
+        let output_dtype = output_dtype.map(str_to_arrow_type).transpose()?;
+        let function = move |s: Series| py_udf_call(&f, s, output_dtype);
+        Ok(self.clone().inner.map(function, output_dtype).into())
+    }
+
+    /// Run an arbitrary Python callable per group, coercing the result to
+    /// `output_dtype`.
+    pub fn apply(&self, f: PyObject, output_dtype: Option<&str>) -> PyResult<PyExpr> {
+        let output_dtype = output_dtype.map(str_to_arrow_type).transpose()?;
+        let function = move |s: Series| py_udf_call(&f, s, output_dtype);
+        Ok(self.clone().inner.apply(function, output_dtype).into())
+    }
 }
 
-fn str_to_arrow_type(s: &str) -> ArrowDataType {
-    match s {
+/// Invoke a Python callable with `s` wrapped in the `PySeries`/`polars.Series`
+/// boundary type (the `Series` in `polars::prelude` is foreign to this crate,
+/// so it can't cross the Python boundary directly), convert the result back,
+/// and coerce it to `output_dtype` when given.
+fn py_udf_call(f: &PyObject, s: Series, output_dtype: Option<ArrowDataType>) -> Result<Series> {
+    Python::with_gil(|py| {
+        let py_series_wrapper = Py::new(py, PySeries::new(s))
+            .map_err(|e| PolarsError::Other(format!("{:?}", e).into()))?;
+        let py_series = py
+            .import("polars")
+            .and_then(|pypolars| pypolars.getattr("Series"))
+            .and_then(|series| series.call_method1("_from_pyseries", (py_series_wrapper,)))
+            .map_err(|e| PolarsError::Other(format!("{:?}", e).into()))?;
+
+        let result = f
+            .call1(py, (py_series,))
+            .map_err(|e| PolarsError::Other(format!("{:?}", e).into()))?;
+
+        let out_series: PySeries = result
+            .getattr(py, "_s")
+            .and_then(|s| s.extract(py))
+            .map_err(|e| PolarsError::Other(format!("{:?}", e).into()))?;
+        let mut out = out_series.series;
+
+        if let Some(dtype) = output_dtype {
+            out = out
+                .cast_with_arrow_datatype(&dtype)
+                .map_err(|e| PolarsError::Other(format!("{:?}", e).into()))?;
+        }
+        Ok(out)
+    })
+}
+
+fn str_to_arrow_type(s: &str) -> PyResult<ArrowDataType> {
+    let dtype = match s {
         "u8" => ArrowDataType::UInt8,
         "u16" => ArrowDataType::UInt16,
         "u32" => ArrowDataType::UInt32,
@@ -212,7 +454,43 @@ fn str_to_arrow_type(s: &str) -> ArrowDataType {
         "f64" => ArrowDataType::Float64,
         "bool" => ArrowDataType::Boolean,
         "utf8" => ArrowDataType::Utf8,
-        _ => todo!(),
+        s => return Err(PyPolarsEr::Other(format!("dtype {} not supported", s)).into()),
+    };
+    Ok(dtype)
+}
+
+/// Returns `true` if `expr`, or any sub-expression it's built from, carries a
+/// closure-backed `Apply`/`Map` node (the shape produced by `.apply()`/
+/// `.map()`, e.g. `str_replace`, `str_contains`, `PyExpr.map`, `PyExpr.apply`)
+/// — those hold a boxed closure that can't round-trip through serde.
+fn contains_closure(expr: &dsl::Expr) -> bool {
+    use dsl::Expr::*;
+    match expr {
+        Apply { .. } | Map { .. } => true,
+        Alias(inner, _) | Not(inner) | IsNull(inner) | IsNotNull(inner) => contains_closure(inner),
+        Cast { expr, .. } | Sort { expr, .. } => contains_closure(expr),
+        Shift { input, .. } => contains_closure(input),
+        BinaryExpr { left, right, .. } => contains_closure(left) || contains_closure(right),
+        Ternary {
+            predicate,
+            truthy,
+            falsy,
+        } => contains_closure(predicate) || contains_closure(truthy) || contains_closure(falsy),
+        Agg(agg) => agg_expr_contains_closure(agg),
+        Column(_) | Literal(_) => false,
+        // Fail closed: a variant this function doesn't have a named arm for
+        // (e.g. whatever `fill_none` builds) is assumed to carry a closure
+        // until proven otherwise, rather than silently letting it through.
+        _ => true,
+    }
+}
+
+fn agg_expr_contains_closure(agg: &AggExpr) -> bool {
+    use AggExpr::*;
+    match agg {
+        Min(e) | Max(e) | Mean(e) | Median(e) | NUnique(e) | First(e) | Last(e) | Sum(e)
+        | Groups(e) => contains_closure(e),
+        Quantile { expr, .. } => contains_closure(expr),
     }
 }
 
@@ -222,6 +500,344 @@ impl From<dsl::Expr> for PyExpr {
     }
 }
 
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Ident(String),
+    Int(i64),
+    Float(f64),
+    Str(String),
+    Op(String),
+    LParen,
+    RParen,
+    Comma,
+    Dot,
+    End,
+}
+
+fn tokenize(s: &str) -> PyResult<Vec<Token>> {
+    let mut chars = s.chars().peekable();
+    let mut tokens = Vec::new();
+
+    while let Some(&c) = chars.peek() {
+        match c {
+            ' ' | '\t' | '\n' => {
+                chars.next();
+            }
+            '(' => {
+                chars.next();
+                tokens.push(Token::LParen);
+            }
+            ')' => {
+                chars.next();
+                tokens.push(Token::RParen);
+            }
+            ',' => {
+                chars.next();
+                tokens.push(Token::Comma);
+            }
+            '.' => {
+                chars.next();
+                tokens.push(Token::Dot);
+            }
+            '"' | '\'' => {
+                let quote = c;
+                chars.next();
+                let mut lit = String::new();
+                loop {
+                    match chars.next() {
+                        Some(c) if c == quote => break,
+                        Some(c) => lit.push(c),
+                        None => {
+                            return Err(
+                                PyPolarsEr::Other("unterminated string literal".into()).into()
+                            )
+                        }
+                    }
+                }
+                tokens.push(Token::Str(lit));
+            }
+            '+' | '-' | '*' | '/' => {
+                chars.next();
+                tokens.push(Token::Op(c.to_string()));
+            }
+            '=' | '!' | '>' | '<' => {
+                chars.next();
+                let mut op = c.to_string();
+                if let Some('=') = chars.peek() {
+                    chars.next();
+                    op.push('=');
+                }
+                tokens.push(Token::Op(op));
+            }
+            c if c.is_ascii_digit() => {
+                let mut num = String::new();
+                let mut is_float = false;
+                while let Some(&c) = chars.peek() {
+                    if c.is_ascii_digit() {
+                        num.push(c);
+                        chars.next();
+                    } else if c == '.' && !is_float {
+                        is_float = true;
+                        num.push(c);
+                        chars.next();
+                    } else {
+                        break;
+                    }
+                }
+                if is_float {
+                    let val = num
+                        .parse::<f64>()
+                        .map_err(|e| PyPolarsEr::Other(format!("{:?}", e)))?;
+                    tokens.push(Token::Float(val));
+                } else {
+                    let val = num
+                        .parse::<i64>()
+                        .map_err(|e| PyPolarsEr::Other(format!("{:?}", e)))?;
+                    tokens.push(Token::Int(val));
+                }
+            }
+            c if c.is_alphabetic() || c == '_' => {
+                let mut ident = String::new();
+                while let Some(&c) = chars.peek() {
+                    if c.is_alphanumeric() || c == '_' {
+                        ident.push(c);
+                        chars.next();
+                    } else {
+                        break;
+                    }
+                }
+                tokens.push(Token::Ident(ident));
+            }
+            c => {
+                return Err(PyPolarsEr::Other(format!("unexpected character: {}", c)).into());
+            }
+        }
+    }
+    tokens.push(Token::End);
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> &Token {
+        &self.tokens[self.pos]
+    }
+
+    fn next(&mut self) -> Token {
+        let tok = self.tokens[self.pos].clone();
+        self.pos += 1;
+        tok
+    }
+
+    fn expect(&mut self, tok: &Token) -> PyResult<()> {
+        if self.peek() == tok {
+            self.next();
+            Ok(())
+        } else {
+            Err(PyPolarsEr::Other(format!("expected {:?}, got {:?}", tok, self.peek())).into())
+        }
+    }
+
+    // comparison <- arithmetic ((== | != | > | >= | < | <=) arithmetic)?
+    fn parse_expr(&mut self) -> PyResult<dsl::Expr> {
+        let lhs = self.parse_arithmetic()?;
+        match self.peek().clone() {
+            Token::Op(op) if matches!(op.as_str(), "==" | "!=" | ">" | ">=" | "<" | "<=") => {
+                self.next();
+                let rhs = self.parse_arithmetic()?;
+                Ok(match op.as_str() {
+                    "==" => lhs.eq(rhs),
+                    "!=" => lhs.neq(rhs),
+                    ">" => lhs.gt(rhs),
+                    ">=" => lhs.gt_eq(rhs),
+                    "<" => lhs.lt(rhs),
+                    "<=" => lhs.lt_eq(rhs),
+                    _ => unreachable!(),
+                })
+            }
+            _ => Ok(lhs),
+        }
+    }
+
+    // arithmetic <- term ((+ | -) term)*
+    fn parse_arithmetic(&mut self) -> PyResult<dsl::Expr> {
+        let mut lhs = self.parse_term()?;
+        loop {
+            match self.peek().clone() {
+                Token::Op(op) if op == "+" || op == "-" => {
+                    self.next();
+                    let rhs = self.parse_term()?;
+                    let operator = if op == "+" {
+                        Operator::Plus
+                    } else {
+                        Operator::Minus
+                    };
+                    lhs = dsl::binary_expr(lhs, operator, rhs);
+                }
+                _ => break,
+            }
+        }
+        Ok(lhs)
+    }
+
+    // term <- factor ((* | /) factor)*
+    fn parse_term(&mut self) -> PyResult<dsl::Expr> {
+        let mut lhs = self.parse_factor()?;
+        loop {
+            match self.peek().clone() {
+                Token::Op(op) if op == "*" || op == "/" => {
+                    self.next();
+                    let rhs = self.parse_factor()?;
+                    let operator = if op == "*" {
+                        Operator::Multiply
+                    } else {
+                        Operator::Divide
+                    };
+                    lhs = dsl::binary_expr(lhs, operator, rhs);
+                }
+                _ => break,
+            }
+        }
+        Ok(lhs)
+    }
+
+    // factor <- "(" expr ")" | "-" factor | when | col | ident "(" args ")" | ident
+    //           | int | float | string
+    fn parse_factor(&mut self) -> PyResult<dsl::Expr> {
+        match self.next() {
+            Token::LParen => {
+                let expr = self.parse_expr()?;
+                self.expect(&Token::RParen)?;
+                Ok(expr)
+            }
+            Token::Int(i) => Ok(dsl::lit(i)),
+            Token::Float(f) => Ok(dsl::lit(f)),
+            Token::Str(s) => Ok(dsl::lit(s)),
+            Token::Op(op) if op == "-" => match self.peek().clone() {
+                Token::Int(i) => {
+                    self.next();
+                    Ok(dsl::lit(-i))
+                }
+                Token::Float(f) => {
+                    self.next();
+                    Ok(dsl::lit(-f))
+                }
+                _ => {
+                    let rhs = self.parse_factor()?;
+                    Ok(dsl::binary_expr(dsl::lit(-1i64), Operator::Multiply, rhs))
+                }
+            },
+            Token::Ident(name) if name == "when" => self.parse_when(),
+            // `col` takes a bare column name (`col(a)`), not a sub-expression,
+            // so it's special-cased ahead of the generic call dispatch below.
+            Token::Ident(name) if name == "col" => self.parse_col(),
+            Token::Ident(name) => {
+                if *self.peek() == Token::LParen {
+                    self.parse_call(&name)
+                } else {
+                    Ok(dsl::col(&name))
+                }
+            }
+            tok => Err(PyPolarsEr::Other(format!("unexpected token: {:?}", tok)).into()),
+        }
+    }
+
+    fn parse_col(&mut self) -> PyResult<dsl::Expr> {
+        self.expect(&Token::LParen)?;
+        let name = match self.next() {
+            Token::Ident(s) => s,
+            Token::Str(s) => s,
+            tok => {
+                return Err(
+                    PyPolarsEr::Other(format!("col expects a column name, got {:?}", tok)).into(),
+                )
+            }
+        };
+        self.expect(&Token::RParen)?;
+        Ok(dsl::col(&name))
+    }
+
+    fn parse_call(&mut self, name: &str) -> PyResult<dsl::Expr> {
+        self.expect(&Token::LParen)?;
+        let mut args = Vec::new();
+        if *self.peek() != Token::RParen {
+            args.push(self.parse_expr()?);
+            while *self.peek() == Token::Comma {
+                self.next();
+                args.push(self.parse_expr()?);
+            }
+        }
+        self.expect(&Token::RParen)?;
+
+        let arg = |i: usize| -> PyResult<dsl::Expr> {
+            args.get(i)
+                .cloned()
+                .ok_or_else(|| PyPolarsEr::Other(format!("{} expects an argument", name)).into())
+        };
+        let str_arg = |i: usize| -> PyResult<String> {
+            match args.get(i) {
+                Some(dsl::Expr::Literal(ScalarValue::Utf8(s))) => Ok(s.clone()),
+                _ => Err(PyPolarsEr::Other(format!("{} expects a string argument", name)).into()),
+            }
+        };
+
+        match name {
+            "sum" => Ok(arg(0)?.sum()),
+            "mean" => Ok(arg(0)?.mean()),
+            "min" => Ok(arg(0)?.min()),
+            "max" => Ok(arg(0)?.max()),
+            "median" => Ok(arg(0)?.median()),
+            "cast" => Ok(arg(0)?.cast(str_to_arrow_type(&str_arg(1)?)?)),
+            "alias" => Ok(arg(0)?.alias(&str_arg(1)?)),
+            _ => Err(PyPolarsEr::Other(format!("unknown function: {}", name)).into()),
+        }
+    }
+
+    // when(predicate).then(expr).otherwise(expr)
+    fn parse_when(&mut self) -> PyResult<dsl::Expr> {
+        self.expect(&Token::LParen)?;
+        let predicate = self.parse_expr()?;
+        self.expect(&Token::RParen)?;
+
+        self.expect(&Token::Dot)?;
+        self.expect_ident("then")?;
+        self.expect(&Token::LParen)?;
+        let then = self.parse_expr()?;
+        self.expect(&Token::RParen)?;
+
+        self.expect(&Token::Dot)?;
+        self.expect_ident("otherwise")?;
+        self.expect(&Token::LParen)?;
+        let otherwise = self.parse_expr()?;
+        self.expect(&Token::RParen)?;
+
+        Ok(dsl::ternary_expr(predicate, then, otherwise))
+    }
+
+    fn expect_ident(&mut self, name: &str) -> PyResult<()> {
+        match self.next() {
+            Token::Ident(ref s) if s == name => Ok(()),
+            tok => Err(PyPolarsEr::Other(format!("expected `{}`, got {:?}", name, tok)).into()),
+        }
+    }
+}
+
+/// Parse a small expression language (e.g. `"col(a) * 2 + col(b) > 10"`) into
+/// the same `dsl::Expr` tree the `PyExpr` methods build, so filters and
+/// projections can be expressed as plain strings.
+#[pyfunction]
+pub fn parse_expr(s: &str) -> PyResult<PyExpr> {
+    let tokens = tokenize(s)?;
+    let mut parser = Parser { tokens, pos: 0 };
+    let expr = parser.parse_expr()?;
+    parser.expect(&Token::End)?;
+    Ok(expr.into())
+}
+
 #[pyclass]
 #[derive(Clone)]
 pub struct When {
@@ -283,4 +899,93 @@ pub fn lit(value: &PyAny) -> PyExpr {
     } else {
         panic!("could not convert type")
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_expr_follows_arithmetic_precedence() {
+        // `2 + 3 * 4` should parse as `2 + (3 * 4)`, not `(2 + 3) * 4`.
+        let got = format!("{:?}", parse_expr("2 + 3 * 4").unwrap().inner);
+        let want = format!(
+            "{:?}",
+            dsl::binary_expr(
+                dsl::lit(2i64),
+                Operator::Plus,
+                dsl::binary_expr(dsl::lit(3i64), Operator::Multiply, dsl::lit(4i64)),
+            )
+        );
+        assert_eq!(got, want);
+    }
+
+    #[test]
+    fn parse_expr_comparison_binds_loosest() {
+        let got = format!(
+            "{:?}",
+            parse_expr("col(a) * 2 + col(b) > 10").unwrap().inner
+        );
+        let lhs = dsl::binary_expr(
+            dsl::binary_expr(dsl::col("a"), Operator::Multiply, dsl::lit(2i64)),
+            Operator::Plus,
+            dsl::col("b"),
+        );
+        let want = format!("{:?}", lhs.gt(dsl::lit(10i64)));
+        assert_eq!(got, want);
+    }
+
+    #[test]
+    fn parse_expr_unary_minus_on_literal() {
+        let got = format!("{:?}", parse_expr("-5").unwrap().inner);
+        let want = format!("{:?}", dsl::lit(-5i64));
+        assert_eq!(got, want);
+    }
+
+    #[test]
+    fn parse_expr_unary_minus_on_sub_expression() {
+        let got = format!("{:?}", parse_expr("-col(a)").unwrap().inner);
+        let want = format!(
+            "{:?}",
+            dsl::binary_expr(dsl::lit(-1i64), Operator::Multiply, dsl::col("a"))
+        );
+        assert_eq!(got, want);
+    }
+
+    #[test]
+    fn parse_expr_rejects_unterminated_string() {
+        assert!(parse_expr("col(\"a)").is_err());
+    }
+
+    #[test]
+    fn parse_expr_rejects_trailing_garbage() {
+        assert!(parse_expr("col(a))").is_err());
+    }
+
+    #[test]
+    fn parse_expr_rejects_unknown_function() {
+        assert!(parse_expr("bogus(col(a))").is_err());
+    }
+
+    #[test]
+    fn to_bytes_round_trips_structural_expressions() {
+        Python::with_gil(|py| {
+            let expr: PyExpr = dsl::col("a").eq(dsl::lit(1i64)).into();
+            let buf: Vec<u8> = expr.to_bytes(py).unwrap().extract(py).unwrap();
+            let round_tripped = PyExpr::from_bytes(&buf).unwrap();
+            assert_eq!(
+                format!("{:?}", round_tripped.inner),
+                format!("{:?}", expr.inner)
+            );
+        });
+    }
+
+    #[test]
+    fn to_bytes_rejects_closure_backed_expressions() {
+        Python::with_gil(|py| {
+            let expr: PyExpr = dsl::col("a").into();
+            let expr = expr.str_lengths();
+            assert!(expr.to_bytes(py).is_err());
+        });
+    }
+}